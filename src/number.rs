@@ -0,0 +1,160 @@
+use std::fmt;
+use std::str;
+
+/// A JSON number.
+///
+/// The numeric token is kept verbatim in `raw` so serialization is
+/// byte-exact, while `kind` records how the value is held. The parser keeps
+/// an integer token exactly — as an `i64` when it is negative, or a `u64`
+/// when it is not — and falls back to `f64` when a `.`, `e` or `E` is seen
+/// or when the integer does not fit either type. This means a pure-integer token such as
+/// `123456789012345678` survives parse → [`to_i64`](#method.to_i64) → print
+/// without drifting through a float.
+#[derive(Clone)]
+pub struct Number {
+    raw: String,
+    kind: Kind,
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    /// A non-negative integer.
+    PosInt(u64),
+    /// A negative integer.
+    NegInt(i64),
+    /// A floating point value (token carried a `.`, `e` or `E`, or was an
+    /// integer out of range for `u64`/`i64`).
+    Float(f64),
+}
+
+impl Number {
+    /// The original numeric digits, exactly as parsed.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns true if the number is an integer that fits in an `i64`.
+    pub fn is_i64(&self) -> bool {
+        match self.kind {
+            Kind::PosInt(u) => u <= i64::max_value() as u64,
+            Kind::NegInt(_) => true,
+            Kind::Float(_) => false,
+        }
+    }
+
+    /// Returns true if the number is held as an unsigned integer.
+    pub fn is_u64(&self) -> bool {
+        matches!(self.kind, Kind::PosInt(_))
+    }
+
+    /// Returns true if the number is held as a float.
+    pub fn is_f64(&self) -> bool {
+        matches!(self.kind, Kind::Float(_))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        match self.kind {
+            Kind::PosInt(u) => u as f64,
+            Kind::NegInt(i) => i as f64,
+            Kind::Float(f) => f,
+        }
+    }
+
+    pub fn to_i64(&self) -> i64 {
+        match self.kind {
+            Kind::PosInt(u) => u as i64,
+            Kind::NegInt(i) => i,
+            Kind::Float(f) => f as i64,
+        }
+    }
+
+    pub fn to_u64(&self) -> u64 {
+        match self.kind {
+            Kind::PosInt(u) => u,
+            Kind::NegInt(i) => i as u64,
+            Kind::Float(f) => f as u64,
+        }
+    }
+}
+
+impl<'a> From<&'a [u8]> for Number {
+    fn from(v: &'a [u8]) -> Number {
+        // Scan the leading numeric token, noting whether it is a float.
+        let mut end = 0;
+        let mut is_float = false;
+        while end < v.len() {
+            match v[end] {
+                b'0'..=b'9' | b'-' | b'+' => {}
+                b'.' | b'e' | b'E' => is_float = true,
+                _ => break,
+            }
+            end += 1;
+        }
+
+        let raw = str::from_utf8(&v[..end]).unwrap_or("").to_owned();
+
+        let kind = if is_float {
+            Kind::Float(raw.parse::<f64>().unwrap_or(0.0))
+        } else if raw.starts_with('-') {
+            match raw.parse::<i64>() {
+                Ok(i) => Kind::NegInt(i),
+                Err(_) => Kind::Float(raw.parse::<f64>().unwrap_or(0.0)),
+            }
+        } else {
+            match raw.parse::<u64>() {
+                Ok(u) => Kind::PosInt(u),
+                Err(_) => Kind::Float(raw.parse::<f64>().unwrap_or(0.0)),
+            }
+        };
+
+        Number { raw, kind }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Number) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl fmt::Debug for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Number;
+
+    #[test]
+    fn large_integer_round_trips() {
+        // Larger than 2^53, so a float detour would corrupt it.
+        let n = Number::from(&b"123456789012345678"[..]);
+        assert!(n.is_i64());
+        assert_eq!(n.to_i64(), 123_456_789_012_345_678_i64);
+        assert_eq!(n.as_str(), "123456789012345678");
+    }
+
+    #[test]
+    fn unsigned_beyond_i64_is_u64() {
+        let n = Number::from(&b"18446744073709551615"[..]);
+        assert!(n.is_u64());
+        assert!(!n.is_i64());
+        assert_eq!(n.to_u64(), u64::max_value());
+    }
+
+    #[test]
+    fn decimal_token_is_float() {
+        let n = Number::from(&b"1.5"[..]);
+        assert!(n.is_f64());
+        assert_eq!(n.to_f64(), 1.5);
+        assert_eq!(n.as_str(), "1.5");
+    }
+}
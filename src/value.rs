@@ -76,6 +76,24 @@ impl Value {
         matches!(self, Value::Number(_))
     }
 
+    /// Returns true if the `Value` is an integer number that fits in an
+    /// `i64`. Floats and integers above `i64::MAX` return false.
+    pub fn is_i64(&self) -> bool {
+        matches!(self, Value::Number(n) if n.is_i64())
+    }
+
+    /// Returns true if the `Value` is a non-negative integer number held as
+    /// a `u64`. This includes small values that also fit in an `i64`.
+    pub fn is_u64(&self) -> bool {
+        matches!(self, Value::Number(n) if n.is_u64())
+    }
+
+    /// Returns true if the `Value` is a number that is held as a float
+    /// (`f64`), i.e. the JSON token contained a `.`, `e` or `E`.
+    pub fn is_f64(&self) -> bool {
+        matches!(self, Value::Number(n) if n.is_f64())
+    }
+
     pub fn is_array(&self) -> bool {
         matches!(self, Value::Array(_))
     }
@@ -140,6 +158,63 @@ impl Value {
         }
     }
 
+    /// If the `Value` is a number, represent it as `f64`. Returns `None`
+    /// otherwise. Unlike [`to_f64`](#method.to_f64) this never coerces a
+    /// string or boolean, so callers can tell "absent / wrong type" apart
+    /// from "present and zero".
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(number) => Some(number.to_f64()),
+            _ => None,
+        }
+    }
+
+    /// If the `Value` is an integer that fits in an `i64`, return it.
+    /// Returns `None` for floats and out-of-range values, so `1.5` and a
+    /// value above `i64::MAX` both yield `None` rather than a truncated or
+    /// wrapped result.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Number(number) if self.is_i64() => Some(number.to_i64()),
+            _ => None,
+        }
+    }
+
+    /// If the `Value` is a non-negative integer that fits in a `u64`, return
+    /// it. Returns `None` for floats and negative values.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(number) if self.is_u64() => Some(number.to_u64()),
+            _ => None,
+        }
+    }
+
+    /// If the `Value` is a boolean, return it. Returns `None` otherwise.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// If the `Value` is an object, return its parsed key/value map.
+    /// Returns `None` otherwise.
+    pub fn as_object(&self) -> Option<HashMap<String, Value>> {
+        match self {
+            Value::Object(s) => Some(Getter::from_str(s).to_object()),
+            _ => None,
+        }
+    }
+
+    /// If the `Value` is an array, return its parsed elements.
+    /// Returns `None` otherwise.
+    pub fn as_array(&self) -> Option<Vec<Value>> {
+        match self {
+            Value::Array(s) => Some(Getter::from_str(s).to_vec()),
+            _ => None,
+        }
+    }
+
     pub fn to_vec(&self) -> Vec<Value> {
         match self {
             Value::Array(s) => Getter::from_str(s).to_vec(),
@@ -156,6 +231,645 @@ impl Value {
     }
 }
 
+impl Value {
+    /// Re-serialize the value back to compact JSON text.
+    ///
+    /// Unlike [`as_str`](#method.as_str), which hands back the raw slice a
+    /// value was parsed from, this walks the value — recursively parsing
+    /// the raw array slices through [`Getter`] and the raw object slices
+    /// member by member in source order — and emits canonical
+    /// JSON with no stray whitespace.
+    /// ```
+    /// let v = ajson::get(r#"{ "a" : [1,  2] }"#, "@this").unwrap();
+    /// assert_eq!(v.to_json_string(), r#"{"a":[1,2]}"#);
+    /// ```
+    pub fn to_json_string(&self) -> String {
+        let mut out = String::new();
+        encode(self, &mut out, None, 0);
+        out
+    }
+
+    /// Re-serialize the value to indented JSON text using two spaces per
+    /// level. For a configurable indent width use [`PrettyEncoder`].
+    pub fn to_json_string_pretty(&self) -> String {
+        PrettyEncoder::new().encode(self)
+    }
+}
+
+/// Indented JSON encoder with a configurable indent width.
+///
+/// ```
+/// use ajson::PrettyEncoder;
+///
+/// let v = ajson::get(r#"{"a":[1,2]}"#, "@this").unwrap();
+/// let mut enc = PrettyEncoder::new();
+/// enc.set_indent(4);
+/// let _ = enc.encode(&v);
+/// ```
+pub struct PrettyEncoder {
+    indent: usize,
+}
+
+impl Default for PrettyEncoder {
+    fn default() -> Self {
+        PrettyEncoder::new()
+    }
+}
+
+impl PrettyEncoder {
+    /// Create an encoder that indents with two spaces per level.
+    pub fn new() -> Self {
+        PrettyEncoder { indent: 2 }
+    }
+
+    /// Set the number of spaces used for one level of indentation.
+    pub fn set_indent(&mut self, indent: usize) -> &mut Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Re-serialize `value` to indented JSON text.
+    pub fn encode(&self, value: &Value) -> String {
+        let mut out = String::new();
+        encode(value, &mut out, Some(self.indent), 0);
+        out
+    }
+}
+
+fn encode(value: &Value, out: &mut String, indent: Option<usize>, depth: usize) {
+    match value {
+        Value::String(s) => encode_str(s, out),
+        Value::Number(number) => out.push_str(number.as_str()),
+        Value::Boolean(true) => out.push_str("true"),
+        Value::Boolean(false) => out.push_str("false"),
+        Value::Null => out.push_str("null"),
+        Value::Array(_) => {
+            let elements = value.to_vec();
+            if elements.is_empty() {
+                out.push_str("[]");
+                return;
+            }
+            out.push('[');
+            for (i, el) in elements.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                push_newline(out, indent, depth + 1);
+                encode(el, out, indent, depth + 1);
+            }
+            push_newline(out, indent, depth);
+            out.push(']');
+        }
+        Value::Object(s) => {
+            let members = object_members(s);
+            if members.is_empty() {
+                out.push_str("{}");
+                return;
+            }
+            out.push('{');
+            for (i, (k, v)) in members.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                push_newline(out, indent, depth + 1);
+                encode_str(k, out);
+                out.push(':');
+                if indent.is_some() {
+                    out.push(' ');
+                }
+                encode(v, out, indent, depth + 1);
+            }
+            push_newline(out, indent, depth);
+            out.push('}');
+        }
+    }
+}
+
+fn push_newline(out: &mut String, indent: Option<usize>, depth: usize) {
+    if let Some(width) = indent {
+        out.push('\n');
+        for _ in 0..width * depth {
+            out.push(' ');
+        }
+    }
+}
+
+fn encode_str(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\x08' => out.push_str("\\b"),
+            '\x0c' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Walk the members of a raw object slice in source order, returning each
+/// key with its parsed value. Unlike [`to_object`](Value::to_object) this
+/// preserves input order, which the serializer needs for stable output.
+fn object_members(s: &str) -> Vec<(String, Value)> {
+    let b = s.as_bytes();
+    let mut members = Vec::new();
+    let mut i = 0;
+    while i < b.len() && b[i] != b'{' {
+        i += 1;
+    }
+    if i >= b.len() {
+        return members;
+    }
+    i += 1; // past '{'
+    loop {
+        while i < b.len() && (b[i].is_ascii_whitespace() || b[i] == b',') {
+            i += 1;
+        }
+        if i >= b.len() || b[i] != b'"' {
+            break;
+        }
+        let (key, ni) = scan_string(b, i);
+        i = ni;
+        while i < b.len() && (b[i].is_ascii_whitespace() || b[i] == b':') {
+            i += 1;
+        }
+        let (value, ni) = scan_value(b, i);
+        i = ni;
+        members.push((key, value));
+    }
+    members
+}
+
+/// Parse a JSON string token starting at the opening quote, returning the
+/// unescaped contents and the index just past the closing quote.
+fn scan_string(b: &[u8], start: usize) -> (String, usize) {
+    let mut i = start + 1;
+    let mut s = String::new();
+    while i < b.len() {
+        match b[i] {
+            b'"' => {
+                i += 1;
+                break;
+            }
+            b'\\' => {
+                i += 1;
+                if i >= b.len() {
+                    break;
+                }
+                match b[i] {
+                    b'"' => s.push('"'),
+                    b'\\' => s.push('\\'),
+                    b'/' => s.push('/'),
+                    b'n' => s.push('\n'),
+                    b'r' => s.push('\r'),
+                    b't' => s.push('\t'),
+                    b'b' => s.push('\u{08}'),
+                    b'f' => s.push('\u{0c}'),
+                    b'u' => {
+                        if let Some(hi) = hex4(b, i + 1) {
+                            if (0xD800..=0xDBFF).contains(&hi)
+                                && i + 10 < b.len()
+                                && b[i + 5] == b'\\'
+                                && b[i + 6] == b'u'
+                            {
+                                // Combine a UTF-16 surrogate pair into one
+                                // scalar before pushing, so astral-plane
+                                // characters survive instead of being dropped.
+                                if let Some(lo) =
+                                    hex4(b, i + 7).filter(|lo| (0xDC00..=0xDFFF).contains(lo))
+                                {
+                                    let cp =
+                                        0x10000 + (((hi - 0xD800) << 10) | (lo - 0xDC00));
+                                    if let Some(ch) = char::from_u32(cp) {
+                                        s.push(ch);
+                                    }
+                                    i += 10;
+                                } else {
+                                    i += 4;
+                                }
+                            } else {
+                                if let Some(ch) = char::from_u32(hi) {
+                                    s.push(ch);
+                                }
+                                i += 4;
+                            }
+                        }
+                    }
+                    other => s.push(other as char),
+                }
+                i += 1;
+            }
+            _ => {
+                let from = i;
+                while i < b.len() && b[i] != b'"' && b[i] != b'\\' {
+                    i += 1;
+                }
+                s.push_str(str::from_utf8(&b[from..i]).unwrap_or(""));
+            }
+        }
+    }
+    (s, i)
+}
+
+/// Parse the four hex digits at `start` into a code unit, if they are valid.
+fn hex4(b: &[u8], start: usize) -> Option<u32> {
+    if start + 4 > b.len() {
+        return None;
+    }
+    let hex = str::from_utf8(&b[start..start + 4]).ok()?;
+    u32::from_str_radix(hex, 16).ok()
+}
+
+/// Parse a single JSON value starting at `start`, returning the value and
+/// the index just past it.
+fn scan_value(b: &[u8], start: usize) -> (Value, usize) {
+    let mut i = start;
+    while i < b.len() && b[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    if i >= b.len() {
+        return (Value::Null, i);
+    }
+    match b[i] {
+        b'"' => {
+            let (s, ni) = scan_string(b, i);
+            (Value::String(s), ni)
+        }
+        b'{' => {
+            let end = scan_balanced(b, i, b'{', b'}');
+            (
+                Value::Object(str::from_utf8(&b[i..end]).unwrap_or("{}").to_owned()),
+                end,
+            )
+        }
+        b'[' => {
+            let end = scan_balanced(b, i, b'[', b']');
+            (
+                Value::Array(str::from_utf8(&b[i..end]).unwrap_or("[]").to_owned()),
+                end,
+            )
+        }
+        b't' => (Value::Boolean(true), i + 4),
+        b'f' => (Value::Boolean(false), i + 5),
+        b'n' => (Value::Null, i + 4),
+        _ => {
+            let from = i;
+            while i < b.len() {
+                match b[i] {
+                    b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E' => i += 1,
+                    _ => break,
+                }
+            }
+            (Value::Number(Number::from(&b[from..i])), i)
+        }
+    }
+}
+
+/// Return the index just past the `close` byte that matches the `open` at
+/// `start`, skipping over nested brackets and quoted strings.
+fn scan_balanced(b: &[u8], start: usize, open: u8, close: u8) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    let mut in_str = false;
+    while i < b.len() {
+        let c = b[i];
+        if in_str {
+            if c == b'\\' {
+                i += 2;
+                continue;
+            }
+            if c == b'"' {
+                in_str = false;
+            }
+        } else if c == b'"' {
+            in_str = true;
+        } else if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return i + 1;
+            }
+        }
+        i += 1;
+    }
+    i
+}
+
+/// A type that can address a sub-value of a [`Value`], by object key or
+/// array position. This backs [`Value::at`]; `str`/`&str` look up an object
+/// key and `usize` an array element.
+pub trait Key {
+    #[doc(hidden)]
+    fn index_into(&self, value: &Value) -> Value;
+}
+
+impl Key for str {
+    fn index_into(&self, value: &Value) -> Value {
+        value.get_by_utf8(self.as_bytes()).unwrap_or(Value::Null)
+    }
+}
+
+impl Key for String {
+    fn index_into(&self, value: &Value) -> Value {
+        self.as_str().index_into(value)
+    }
+}
+
+impl Key for usize {
+    fn index_into(&self, value: &Value) -> Value {
+        value.get(&self.to_string()).unwrap_or(Value::Null)
+    }
+}
+
+impl<'a, T> Key for &'a T
+where
+    T: ?Sized + Key,
+{
+    fn index_into(&self, value: &Value) -> Value {
+        (**self).index_into(value)
+    }
+}
+
+impl Value {
+    /// Look up an object key or array element, returning an owned `Value`.
+    ///
+    /// This is a total accessor — a missing key or out-of-range index yields
+    /// [`Value::Null`] rather than `None` — so chains like
+    /// `v.at("address").at("city")` and `v.at(0)` stay readable.
+    ///
+    /// Note: this is deliberately *not* the `[]` operator. `std::ops::Index`
+    /// must return a borrow, but a `Value` stores the raw source slice and
+    /// produces its children owned on access, so there is no existing
+    /// sub-value to borrow. A sound `[]` would require `Value` to own a node
+    /// tree; returning a leaked `&'static` to fake it is not acceptable, so
+    /// the crate exposes this owning accessor instead.
+    /// ```
+    /// let v = ajson::get(r#"{"address":{"city":"NYC"}}"#, "@this").unwrap();
+    /// assert_eq!(v.at("address").at("city"), "NYC");
+    /// ```
+    pub fn at<K: Key>(&self, key: K) -> Value {
+        key.index_into(self)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Value {
+        Value::Number(Number::from(n.to_string().as_bytes()))
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Value {
+        if n.is_finite() {
+            Value::Number(Number::from(n.to_string().as_bytes()))
+        } else {
+            // NaN and +/-Infinity have no JSON representation, so they map to
+            // null rather than emitting the invalid tokens `NaN`/`inf`.
+            Value::Null
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Value {
+        Value::Boolean(b)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Value {
+        Value::String(s.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::String(s)
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(elements: Vec<Value>) -> Value {
+        let mut out = String::from("[");
+        for (i, el) in elements.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            encode(el, &mut out, None, 0);
+        }
+        out.push(']');
+        Value::Array(out)
+    }
+}
+
+impl From<Vec<(String, Value)>> for Value {
+    fn from(members: Vec<(String, Value)>) -> Value {
+        let mut out = String::from("{");
+        for (i, (k, v)) in members.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            encode_str(k, &mut out);
+            out.push(':');
+            encode(v, &mut out, None, 0);
+        }
+        out.push('}');
+        Value::Object(out)
+    }
+}
+
+impl From<HashMap<String, Value>> for Value {
+    fn from(members: HashMap<String, Value>) -> Value {
+        // A HashMap has no inherent order, so sort by key to keep the stored
+        // representation deterministic across runs.
+        let mut members: Vec<(String, Value)> = members.into_iter().collect();
+        members.sort_by(|a, b| a.0.cmp(&b.0));
+        Value::from(members)
+    }
+}
+
+/// Construct a [`Value`] from a JSON literal.
+///
+/// ```
+/// use ajson::json;
+///
+/// let v = json!({
+///     "name": "ajson",
+///     "stars": 42,
+///     "tags": ["json", "query"]
+/// });
+/// assert_eq!(v.get("stars").unwrap().to_i64(), 42);
+/// ```
+#[macro_export]
+macro_rules! json {
+    ($($json:tt)+) => {
+        $crate::json_internal!($($json)+)
+    };
+}
+
+/// Implementation detail of [`json!`]. A token-tree muncher that parses the
+/// literal one token at a time, so multi-token values such as negative
+/// numbers (`-5`) work in array and object position, not only at top level.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! json_internal {
+    // Parsing the inside of an array `[...]` into a `vec![...]` of values.
+    // Invoked as: json_internal!(@array [] $($tt)*)
+
+    // Done with trailing comma.
+    (@array [$($elems:expr,)*]) => {
+        vec![$($elems,)*]
+    };
+
+    // Done without trailing comma.
+    (@array [$($elems:expr),*]) => {
+        vec![$($elems),*]
+    };
+
+    // Next element is `null`.
+    (@array [$($elems:expr,)*] null $($rest:tt)*) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json_internal!(null)] $($rest)*)
+    };
+
+    // Next element is `true`.
+    (@array [$($elems:expr,)*] true $($rest:tt)*) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json_internal!(true)] $($rest)*)
+    };
+
+    // Next element is `false`.
+    (@array [$($elems:expr,)*] false $($rest:tt)*) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json_internal!(false)] $($rest)*)
+    };
+
+    // Next element is an array.
+    (@array [$($elems:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json_internal!([$($array)*])] $($rest)*)
+    };
+
+    // Next element is a map.
+    (@array [$($elems:expr,)*] {$($map:tt)*} $($rest:tt)*) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json_internal!({$($map)*})] $($rest)*)
+    };
+
+    // Next element is an expression followed by a comma.
+    (@array [$($elems:expr,)*] $next:expr, $($rest:tt)*) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json_internal!($next),] $($rest)*)
+    };
+
+    // Last element is an expression with no trailing comma.
+    (@array [$($elems:expr,)*] $last:expr) => {
+        $crate::json_internal!(@array [$($elems,)* $crate::json_internal!($last)])
+    };
+
+    // Comma after the most recent element.
+    (@array [$($elems:expr),*] , $($rest:tt)*) => {
+        $crate::json_internal!(@array [$($elems,)*] $($rest)*)
+    };
+
+    // Parsing the inside of an object `{...}`, pushing `(key, value)` pairs
+    // onto `$object` in source order.
+    // Invoked as: json_internal!(@object $object () ($($tt)*) ($($tt)*))
+
+    // Done.
+    (@object $object:ident () () ()) => {};
+
+    // Insert the current entry, followed by a trailing comma.
+    (@object $object:ident [$($key:tt)+] ($value:expr) , $($rest:tt)*) => {
+        $object.push((($($key)+).to_string(), $value));
+        $crate::json_internal!(@object $object () ($($rest)*) ($($rest)*));
+    };
+
+    // Insert the last entry, without a trailing comma.
+    (@object $object:ident [$($key:tt)+] ($value:expr)) => {
+        $object.push((($($key)+).to_string(), $value));
+    };
+
+    // Next value is `null`.
+    (@object $object:ident ($($key:tt)+) (: null $($rest:tt)*) $copy:tt) => {
+        $crate::json_internal!(@object $object [$($key)+] ($crate::json_internal!(null)) $($rest)*);
+    };
+
+    // Next value is `true`.
+    (@object $object:ident ($($key:tt)+) (: true $($rest:tt)*) $copy:tt) => {
+        $crate::json_internal!(@object $object [$($key)+] ($crate::json_internal!(true)) $($rest)*);
+    };
+
+    // Next value is `false`.
+    (@object $object:ident ($($key:tt)+) (: false $($rest:tt)*) $copy:tt) => {
+        $crate::json_internal!(@object $object [$($key)+] ($crate::json_internal!(false)) $($rest)*);
+    };
+
+    // Next value is an array.
+    (@object $object:ident ($($key:tt)+) (: [$($array:tt)*] $($rest:tt)*) $copy:tt) => {
+        $crate::json_internal!(@object $object [$($key)+] ($crate::json_internal!([$($array)*])) $($rest)*);
+    };
+
+    // Next value is a map.
+    (@object $object:ident ($($key:tt)+) (: {$($map:tt)*} $($rest:tt)*) $copy:tt) => {
+        $crate::json_internal!(@object $object [$($key)+] ($crate::json_internal!({$($map)*})) $($rest)*);
+    };
+
+    // Next value is an expression followed by a comma.
+    (@object $object:ident ($($key:tt)+) (: $value:expr , $($rest:tt)*) $copy:tt) => {
+        $crate::json_internal!(@object $object [$($key)+] ($crate::json_internal!($value)) , $($rest)*);
+    };
+
+    // Last value is an expression with no trailing comma.
+    (@object $object:ident ($($key:tt)+) (: $value:expr) $copy:tt) => {
+        $crate::json_internal!(@object $object [$($key)+] ($crate::json_internal!($value)));
+    };
+
+    // Munch a token into the current key.
+    (@object $object:ident ($($key:tt)*) ($tt:tt $($rest:tt)*) $copy:tt) => {
+        $crate::json_internal!(@object $object ($($key)* $tt) ($($rest)*) ($($rest)*));
+    };
+
+    // Primary rules.
+
+    (null) => {
+        $crate::Value::Null
+    };
+
+    (true) => {
+        $crate::Value::Boolean(true)
+    };
+
+    (false) => {
+        $crate::Value::Boolean(false)
+    };
+
+    ([]) => {
+        $crate::Value::from(std::vec::Vec::<$crate::Value>::new())
+    };
+
+    ([ $($tt:tt)+ ]) => {
+        $crate::Value::from($crate::json_internal!(@array [] $($tt)+))
+    };
+
+    ({}) => {
+        $crate::Value::from(std::vec::Vec::<(std::string::String, $crate::Value)>::new())
+    };
+
+    ({ $($tt:tt)+ }) => {
+        $crate::Value::from({
+            let mut object: std::vec::Vec<(std::string::String, $crate::Value)> =
+                std::vec::Vec::new();
+            $crate::json_internal!(@object object () ($($tt)+) ($($tt)+));
+            object
+        })
+    };
+
+    ($other:expr) => {
+        $crate::Value::from($other)
+    };
+}
+
 impl<'a> cmp::PartialEq<&'a str> for Value {
     fn eq(&self, other: &&str) -> bool {
         self.as_str() == *other
@@ -167,3 +881,40 @@ impl cmp::PartialEq<f64> for Value {
         self.to_f64() == *other
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    #[test]
+    fn encodes_nested_objects_in_arrays() {
+        let v = Value::Array(r#"[{"a":1},{"b":2}]"#.to_owned());
+        assert_eq!(v.to_json_string(), r#"[{"a":1},{"b":2}]"#);
+    }
+
+    #[test]
+    fn encodes_escaped_keys() {
+        let v = Value::Object(r#"{"a\"b":1}"#.to_owned());
+        assert_eq!(v.to_json_string(), r#"{"a\"b":1}"#);
+    }
+
+    #[test]
+    fn preserves_large_integer_member() {
+        // A float detour would corrupt this >2^53 member.
+        let v = Value::Object(r#"{"n":123456789012345678}"#.to_owned());
+        assert_eq!(v.to_json_string(), r#"{"n":123456789012345678}"#);
+    }
+
+    #[test]
+    fn json_macro_handles_negative_numbers() {
+        let v = json!({"t": -1, "a": [-2, 3]});
+        assert_eq!(v.to_json_string(), r#"{"t":-1,"a":[-2,3]}"#);
+    }
+
+    #[test]
+    fn combines_surrogate_pairs() {
+        // A lone surrogate would decode to nothing; the pair must combine.
+        let v = Value::Object("{\"e\":\"\\uD83D\\uDE00\"}".to_owned());
+        assert_eq!(v.to_json_string(), "{\"e\":\"\u{1F600}\"}");
+    }
+}